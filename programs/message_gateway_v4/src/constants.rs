@@ -0,0 +1,24 @@
+/// PDA seed prefixes.
+pub const GATEWAY_SEED: &[u8] = b"gateway";
+pub const COUNTER_SEED: &[u8] = b"counter";
+pub const TX_SEED: &[u8] = b"tx";
+pub const VALIDATOR_SET_SEED: &[u8] = b"validator_set";
+pub const SIGNATURE_SET_SEED: &[u8] = b"signature_set";
+
+/// Maximum sizes for variable-length message fields (DOS protection).
+pub const MAX_SENDER_SIZE: usize = 64;
+pub const MAX_RECIPIENT_SIZE: usize = 64;
+pub const MAX_ON_CHAIN_DATA_SIZE: usize = 1024;
+pub const MAX_OFF_CHAIN_DATA_SIZE: usize = 1024;
+
+/// Maximum number of validators in a set. Bounded above by the `u128`
+/// signed-validators bitmap in `SignatureSetPDA`.
+pub const MAX_VALIDATORS: usize = 19;
+
+/// Number of slots a superseded validator set stays valid after a rotation so
+/// in-flight messages signed under it can still be processed (~1 day @ 400ms).
+pub const VALIDATOR_SET_GRACE_SLOTS: u64 = 216_000;
+
+/// Default sliding acceptance window below the high-water mark applied to a
+/// freshly initialized counter.
+pub const DEFAULT_TX_ID_WINDOW: u128 = 10_000;
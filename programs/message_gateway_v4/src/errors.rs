@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum GatewayError {
+    #[msg("Sender address exceeds the maximum allowed size")]
+    SenderTooLong,
+    #[msg("Recipient address exceeds the maximum allowed size")]
+    RecipientTooLong,
+    #[msg("On-chain data exceeds the maximum allowed size")]
+    OnChainDataTooLarge,
+    #[msg("Off-chain data exceeds the maximum allowed size")]
+    OffChainDataTooLarge,
+    #[msg("Transaction id is older than the accepted window")]
+    TxIdTooOld,
+    #[msg("Source chain id is out of range")]
+    InvalidChainId,
+    #[msg("Message is addressed to a different destination chain")]
+    InvalidDestinationChain,
+    #[msg("Signer is not the gateway authority")]
+    UnauthorizedAccess,
+    #[msg("Gateway is disabled")]
+    GatewayDisabled,
+    #[msg("Signatures do not meet the validator quorum")]
+    BelowQuorum,
+    #[msg("Signer is not a member of the active validator set")]
+    UnknownSigner,
+    #[msg("Ed25519 signature verification failed")]
+    InvalidSignature,
+    #[msg("Validator set has expired")]
+    ValidatorSetExpired,
+    #[msg("Validator set must not be empty")]
+    EmptyValidatorSet,
+    #[msg("Validator set exceeds the maximum size")]
+    TooManyValidators,
+    #[msg("New validator set index must be the successor of the current set")]
+    InvalidSetIndex,
+    #[msg("Signature set was committed for a different message hash")]
+    SignatureSetHashMismatch,
+    #[msg("Signature set was committed for a different validator set")]
+    SignatureSetIndexMismatch,
+    #[msg("Malformed Ed25519 program instruction")]
+    MalformedEd25519Ix,
+    #[msg("Ed25519 instruction signs a different message")]
+    Ed25519MessageMismatch,
+}
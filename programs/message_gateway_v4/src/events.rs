@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct CounterInitialized {
+    pub source_chain_id: u64,
+    pub counter_pda: Pubkey,
+    pub authority: Pubkey,
+    pub gateway: Pubkey,
+    pub highest_tx_id_seen: u128,
+}
+
+#[event]
+pub struct TxPdaCreated {
+    pub tx_id: u128,
+    pub source_chain_id: u64,
+}
+
+#[event]
+pub struct ValidatorSetInitialized {
+    pub set_index: u64,
+    pub validator_count: u64,
+    pub created_slot: u64,
+}
+
+#[event]
+pub struct ValidatorSetUpgraded {
+    pub new_set_index: u64,
+    pub previous_set_index: u64,
+    pub validator_count: u64,
+    pub previous_expiry_slot: u64,
+}
+
+#[event]
+pub struct CounterWindowUpdated {
+    pub source_chain_id: u64,
+    pub previous_window: u128,
+    pub accept_window: u128,
+}
@@ -3,9 +3,11 @@ use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::errors::GatewayError;
 use crate::events::TxPdaCreated;
-use crate::state::{CounterPDA, TxIdPDA, MessageSignature};
+use crate::state::{CounterPDA, MessageGateway, SignatureSetPDA, TxIdPDA, ValidatorSetPDA};
 use crate::utils::hash::create_message_hash_for_signing;
+use crate::utils::signature::quorum_threshold;
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<CreateTxPda>,
     tx_id: u128,
@@ -15,16 +17,19 @@ pub fn handler(
     recipient: Vec<u8>,
     on_chain_data: Vec<u8>,
     off_chain_data: Vec<u8>,
-    signatures: Vec<MessageSignature>,
+    active_set_index: u64,
+    message_hash: [u8; 32],
 ) -> Result<()> {
     // Input validation for DOS protection
     require!(sender.len() <= MAX_SENDER_SIZE, GatewayError::SenderTooLong);
     require!(recipient.len() <= MAX_RECIPIENT_SIZE, GatewayError::RecipientTooLong);
     require!(on_chain_data.len() <= MAX_ON_CHAIN_DATA_SIZE, GatewayError::OnChainDataTooLarge);
     require!(off_chain_data.len() <= MAX_OFF_CHAIN_DATA_SIZE, GatewayError::OffChainDataTooLarge);
-    
-    // Create message hash for signature validation
-    let message_hash = create_message_hash_for_signing(
+
+    // The message hash doubles as a signature_set PDA seed, so it is passed in;
+    // recompute it from the message fields and reject any mismatch to stop a
+    // relayer from pointing at a signature set committed for a different hash.
+    let expected_hash = create_message_hash_for_signing(
         tx_id,
         source_chain_id,
         dest_chain_id,
@@ -33,39 +38,73 @@ pub fn handler(
         &on_chain_data,
         &off_chain_data,
     )?;
-    
-    // TX1 basic signature validation (cryptographic verification only)
-    // COMMENTED OUT FOR TESTING - No signature validation needed
-    // validate_signatures_tx1(&signatures, &message_hash, &ctx.accounts.instructions)?;
-    
+    require!(
+        message_hash == expected_hash,
+        GatewayError::SignatureSetHashMismatch
+    );
+
+    // Only process messages addressed to this deployment's destination chain.
+    require!(
+        dest_chain_id == ctx.accounts.gateway.chain_id,
+        GatewayError::InvalidDestinationChain
+    );
+
+    // Resolve the validator set the message was signed under and reject it if
+    // that set has aged out of its grace window.
+    let validator_set = &ctx.accounts.validator_set;
+    require!(
+        !validator_set.is_expired(Clock::get()?.slot),
+        GatewayError::ValidatorSetExpired
+    );
+
+    // Consume the accumulated signature set. Its PDA is seeded by message_hash,
+    // so a set started for a different message resolves to a different address
+    // and cannot be substituted here; it must verify against the same set and
+    // carry a quorum of distinct signatures.
+    let sig_set = &ctx.accounts.signature_set;
+    require!(
+        sig_set.set_index == active_set_index,
+        GatewayError::SignatureSetIndexMismatch
+    );
+    require!(
+        sig_set.signed_count() as usize >= quorum_threshold(validator_set.validators.len()),
+        GatewayError::BelowQuorum
+    );
+
     // Initialize TxId PDA (proves this tx_id hasn't been processed)
     let tx_pda = &mut ctx.accounts.tx_id_pda;
     tx_pda.tx_id = tx_id;
     tx_pda.bump = ctx.bumps.tx_id_pda;
-    
+
     // Counter PDA must already exist (created by authority via initialize_counter)
     let counter = &mut ctx.accounts.counter_pda;
 
-    // Validate TX ID ordering - must be greater than highest seen
+    // Replay is already prevented by the `init` on the TxId PDA above: a given
+    // tx_id can only ever be consumed once. The counter therefore no longer
+    // enforces strict monotonic ordering - which breaks legitimately
+    // out-of-order delivery - and instead rejects only messages older than the
+    // sliding lag window below the high-water mark (assumed finalized/pruned).
     require!(
-        tx_id > counter.highest_tx_id_seen,
+        tx_id.saturating_add(counter.accept_window) >= counter.highest_tx_id_seen,
         GatewayError::TxIdTooOld
     );
 
-    // Update Counter PDA with highest tx_id seen
-    counter.highest_tx_id_seen = tx_id;
-    
+    // Advance the high-water mark for monitoring; it no longer gates acceptance.
+    if tx_id > counter.highest_tx_id_seen {
+        counter.highest_tx_id_seen = tx_id;
+    }
+
     emit!(TxPdaCreated {
         tx_id,
         source_chain_id,
     });
-    
+
     msg!("TxId PDA created for tx_id={}", tx_id);
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(tx_id: u128, source_chain_id: u64, dest_chain_id: u64, sender: Vec<u8>, recipient: Vec<u8>, on_chain_data: Vec<u8>, off_chain_data: Vec<u8>, signatures: Vec<MessageSignature>)]
+#[instruction(tx_id: u128, source_chain_id: u64, dest_chain_id: u64, sender: Vec<u8>, recipient: Vec<u8>, on_chain_data: Vec<u8>, off_chain_data: Vec<u8>, active_set_index: u64, message_hash: [u8; 32])]
 pub struct CreateTxPda<'info> {
     #[account(
         init,
@@ -79,7 +118,7 @@ pub struct CreateTxPda<'info> {
         bump
     )]
     pub tx_id_pda: Account<'info, TxIdPDA>,
-    
+
     #[account(
         mut,
         seeds = [
@@ -89,13 +128,40 @@ pub struct CreateTxPda<'info> {
         bump
     )]
     pub counter_pda: Account<'info, CounterPDA>,
-    
+
+    // Gateway account; the destination chain_id identifies this deployment.
+    #[account(
+        seeds = [GATEWAY_SEED, gateway.chain_id.to_le_bytes().as_ref()],
+        bump = gateway.bump,
+        constraint = gateway.system_enabled @ GatewayError::GatewayDisabled
+    )]
+    pub gateway: Account<'info, MessageGateway>,
+
+    // Validator set the message was signed under, resolved by active_set_index.
+    // May be a superseded set that is still within its grace window.
+    #[account(
+        seeds = [VALIDATOR_SET_SEED, active_set_index.to_le_bytes().as_ref()],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, ValidatorSetPDA>,
+
+    // Accumulated signatures for this message, closed once the tx PDA is
+    // created so its rent is refunded to the relayer.
+    #[account(
+        mut,
+        close = relayer,
+        seeds = [
+            SIGNATURE_SET_SEED,
+            source_chain_id.to_le_bytes().as_ref(),
+            &tx_id.to_le_bytes(),
+            message_hash.as_ref()
+        ],
+        bump = signature_set.bump
+    )]
+    pub signature_set: Account<'info, SignatureSetPDA>,
+
     #[account(mut)]
     pub relayer: Signer<'info>,
-    
-    /// CHECK: Instructions sysvar for Ed25519 signature verification
-    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
-    pub instructions: AccountInfo<'info>,
-    
+
     pub system_program: Program<'info, System>,
-}
\ No newline at end of file
+}
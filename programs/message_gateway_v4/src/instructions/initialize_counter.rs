@@ -18,6 +18,9 @@ const MAX_SUPPORTED_CHAIN_ID: u64 = 18446744073709551615;
 ///
 /// ## Parameters
 /// - `source_chain_id`: The blockchain ID to track messages from (must be > 0 and <= MAX_SUPPORTED_CHAIN_ID)
+// MAX_SUPPORTED_CHAIN_ID is u64::MAX, so the upper bound is always satisfied;
+// it is kept to document the intended contract on chain ids.
+#[allow(clippy::absurd_extreme_comparisons)]
 pub fn handler(
     ctx: Context<InitializeCounter>,
     source_chain_id: u64,
@@ -37,6 +40,7 @@ pub fn handler(
     let counter = &mut ctx.accounts.counter_pda;
     counter.source_chain_id = source_chain_id;
     counter.highest_tx_id_seen = 0;
+    counter.accept_window = DEFAULT_TX_ID_WINDOW;
     counter.bump = ctx.bumps.counter_pda;
 
     // Emit initialization event
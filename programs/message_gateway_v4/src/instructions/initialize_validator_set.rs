@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::GatewayError;
+use crate::events::ValidatorSetInitialized;
+use crate::state::{MessageGateway, ValidatorSetPDA};
+
+/// Installs the genesis validator set (set index 0) for the gateway.
+///
+/// ## Security Considerations
+/// - Only the gateway authority may install a validator set
+/// - Uses `init` to prevent re-initialization of an existing set index
+/// - Rejects empty sets and sets larger than `MAX_VALIDATORS`
+///
+/// ## Parameters
+/// - `set_index`: Version of the set being installed (0 for genesis)
+/// - `validators`: Ordered list of authorized signer public keys
+pub fn handler(
+    ctx: Context<InitializeValidatorSet>,
+    set_index: u64,
+    validators: Vec<Pubkey>,
+) -> Result<()> {
+    require!(!validators.is_empty(), GatewayError::EmptyValidatorSet);
+    require!(
+        validators.len() <= MAX_VALIDATORS,
+        GatewayError::TooManyValidators
+    );
+
+    let current_slot = Clock::get()?.slot;
+
+    let validator_set = &mut ctx.accounts.validator_set;
+    validator_set.set_index = set_index;
+    validator_set.validators = validators;
+    validator_set.created_slot = current_slot;
+    validator_set.expiry_slot = 0;
+    validator_set.bump = ctx.bumps.validator_set;
+
+    emit!(ValidatorSetInitialized {
+        set_index,
+        validator_count: validator_set.validators.len() as u64,
+        created_slot: current_slot,
+    });
+
+    msg!(
+        "Validator set installed: set_index={}, validators={}",
+        set_index,
+        validator_set.validators.len()
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(set_index: u64)]
+pub struct InitializeValidatorSet<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ValidatorSetPDA::SIZE,
+        seeds = [VALIDATOR_SET_SEED, set_index.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub validator_set: Account<'info, ValidatorSetPDA>,
+
+    // Gateway authority - only they can install validator sets
+    #[account(
+        mut,
+        constraint = authority.key() == gateway.authority @ GatewayError::UnauthorizedAccess
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GATEWAY_SEED, gateway.chain_id.to_le_bytes().as_ref()],
+        bump = gateway.bump,
+        constraint = gateway.system_enabled @ GatewayError::GatewayDisabled
+    )]
+    pub gateway: Account<'info, MessageGateway>,
+
+    pub system_program: Program<'info, System>,
+}
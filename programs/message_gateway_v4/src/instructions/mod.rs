@@ -0,0 +1,21 @@
+pub mod create_tx_pda;
+pub mod initialize_counter;
+pub mod initialize_validator_set;
+pub mod post_signatures;
+pub mod set_counter_window;
+pub mod upgrade_validator_set;
+
+// Glob re-exports so the #[program] macro can resolve each instruction's
+// generated client-accounts modules. Each instruction file names its entry
+// point `handler`, so the glob collides on that symbol; it is always called
+// module-qualified (e.g. `create_tx_pda::handler`), so the ambiguity is benign.
+#[allow(ambiguous_glob_reexports)]
+mod reexports {
+    pub use super::create_tx_pda::*;
+    pub use super::initialize_counter::*;
+    pub use super::initialize_validator_set::*;
+    pub use super::post_signatures::*;
+    pub use super::set_counter_window::*;
+    pub use super::upgrade_validator_set::*;
+}
+pub use reexports::*;
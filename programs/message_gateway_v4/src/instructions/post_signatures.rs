@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::GatewayError;
+use crate::state::{SignatureSetPDA, ValidatorSetPDA};
+use crate::utils::ed25519::verify_ed25519_precompile;
+
+/// Records a batch of validator indices into the `SignatureSetPDA` for a
+/// message, after confirming each index is backed by a native Ed25519 verify
+/// instruction over the committed `message_hash` in the same transaction.
+///
+/// The PDA is keyed by `(source_chain_id, tx_id, message_hash)`, so a set
+/// opened for one message can never collide with - or be griefed by - a set
+/// opened for a different one; the first batch commits the set index. This lets
+/// relayers spread a large validator set's signatures across several
+/// transactions without exceeding the transaction size limit. Only validator
+/// indices are posted - the signature bytes live in the paired precompile
+/// instructions, which is what the two-phase flow exists to keep small.
+///
+/// ## Parameters
+/// - `source_chain_id` / `tx_id`: Identify the message these signatures belong to
+/// - `active_set_index`: Validator set the signatures are produced under
+/// - `message_hash`: The signing hash this set commits to (also a PDA seed)
+/// - `indices`: Validator indices to verify against the precompile and record
+pub fn handler(
+    ctx: Context<PostSignatures>,
+    source_chain_id: u64,
+    tx_id: u128,
+    active_set_index: u64,
+    message_hash: [u8; 32],
+    indices: Vec<u8>,
+) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    require!(
+        !validator_set.is_expired(Clock::get()?.slot),
+        GatewayError::ValidatorSetExpired
+    );
+
+    let sig_set = &mut ctx.accounts.signature_set;
+
+    if !sig_set.initialized {
+        // First batch commits the message hash and the set it is signed under.
+        sig_set.source_chain_id = source_chain_id;
+        sig_set.tx_id = tx_id;
+        sig_set.set_index = active_set_index;
+        sig_set.message_hash = message_hash;
+        sig_set.signed_bitmap = 0;
+        sig_set.initialized = true;
+        sig_set.bump = ctx.bumps.signature_set;
+    } else {
+        // The message hash is pinned by the PDA seeds; later batches need only
+        // agree on the set index they verify against.
+        require!(
+            sig_set.set_index == active_set_index,
+            GatewayError::SignatureSetIndexMismatch
+        );
+    }
+
+    // Offload curve math to the native Ed25519 precompile: the relayer pairs
+    // this instruction with one Ed25519 verify instruction per index in the
+    // batch, and we cross-check those against the committed message hash and the
+    // active validator set rather than verifying in BPF.
+    let verified = verify_ed25519_precompile(
+        &ctx.accounts.instructions,
+        &validator_set.validators,
+        &sig_set.message_hash,
+    )?;
+
+    for &index in indices.iter() {
+        require!(
+            (index as usize) < validator_set.validators.len(),
+            GatewayError::UnknownSigner
+        );
+        // Every posted index must be backed by a native verify instruction;
+        // a missing precompile instruction leaves the bit clear and fails here.
+        require!(
+            verified & (1u128 << index) != 0,
+            GatewayError::InvalidSignature
+        );
+
+        sig_set.signed_bitmap |= 1u128 << index;
+    }
+
+    msg!(
+        "Posted {} signatures for tx_id={}, signed_count={}",
+        indices.len(),
+        tx_id,
+        sig_set.signed_count()
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(source_chain_id: u64, tx_id: u128, active_set_index: u64, message_hash: [u8; 32])]
+pub struct PostSignatures<'info> {
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        space = 8 + SignatureSetPDA::SIZE,
+        seeds = [
+            SIGNATURE_SET_SEED,
+            source_chain_id.to_le_bytes().as_ref(),
+            &tx_id.to_le_bytes(),
+            message_hash.as_ref()
+        ],
+        bump
+    )]
+    pub signature_set: Account<'info, SignatureSetPDA>,
+
+    #[account(
+        seeds = [VALIDATOR_SET_SEED, active_set_index.to_le_bytes().as_ref()],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, ValidatorSetPDA>,
+
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    /// CHECK: Instructions sysvar for native Ed25519 signature verification
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
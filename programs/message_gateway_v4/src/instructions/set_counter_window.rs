@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::GatewayError;
+use crate::events::CounterWindowUpdated;
+use crate::state::{CounterPDA, MessageGateway};
+
+/// Tunes the sliding acceptance window on a source chain's counter.
+///
+/// The window bounds how far below the high-water mark an out-of-order
+/// `tx_id` may still be accepted; messages older than
+/// `highest_tx_id_seen - accept_window` are rejected as already
+/// finalized/pruned. Exactly-once semantics are preserved regardless of the
+/// window by the per-`tx_id` PDA.
+///
+/// ## Parameters
+/// - `source_chain_id`: The source chain whose counter is being tuned
+/// - `accept_window`: New lag window below the high-water mark
+pub fn handler(
+    ctx: Context<SetCounterWindow>,
+    _source_chain_id: u64,
+    accept_window: u128,
+) -> Result<()> {
+    let counter = &mut ctx.accounts.counter_pda;
+    let previous_window = counter.accept_window;
+    counter.accept_window = accept_window;
+
+    emit!(CounterWindowUpdated {
+        source_chain_id: counter.source_chain_id,
+        previous_window,
+        accept_window,
+    });
+
+    msg!(
+        "Counter window updated: chain_id={}, window {} -> {}",
+        counter.source_chain_id,
+        previous_window,
+        accept_window
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(source_chain_id: u64)]
+pub struct SetCounterWindow<'info> {
+    #[account(
+        mut,
+        seeds = [
+            COUNTER_SEED,
+            source_chain_id.to_le_bytes().as_ref()
+        ],
+        bump = counter_pda.bump
+    )]
+    pub counter_pda: Account<'info, CounterPDA>,
+
+    // Gateway authority - only they can tune the acceptance window
+    #[account(
+        constraint = authority.key() == gateway.authority @ GatewayError::UnauthorizedAccess
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GATEWAY_SEED, gateway.chain_id.to_le_bytes().as_ref()],
+        bump = gateway.bump,
+        constraint = gateway.system_enabled @ GatewayError::GatewayDisabled
+    )]
+    pub gateway: Account<'info, MessageGateway>,
+}
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::GatewayError;
+use crate::events::ValidatorSetUpgraded;
+use crate::state::{MessageGateway, ValidatorSetPDA};
+
+/// Rotates the authorized signer set, letting operators replace compromised or
+/// departing validators without redeploying the program.
+///
+/// Following the guardian-set-upgrade pattern, the new set is installed at the
+/// next index while the outgoing set stays valid for `VALIDATOR_SET_GRACE_SLOTS`
+/// more slots so in-flight messages signed under it can still be processed.
+///
+/// ## Parameters
+/// - `new_set_index`: Must equal `current_set.set_index + 1`
+/// - `validators`: Ordered list of authorized signer public keys for the new set
+pub fn handler(
+    ctx: Context<UpgradeValidatorSet>,
+    new_set_index: u64,
+    validators: Vec<Pubkey>,
+) -> Result<()> {
+    require!(!validators.is_empty(), GatewayError::EmptyValidatorSet);
+    require!(
+        validators.len() <= MAX_VALIDATORS,
+        GatewayError::TooManyValidators
+    );
+
+    let current_set = &mut ctx.accounts.current_set;
+    require!(
+        new_set_index == current_set.set_index + 1,
+        GatewayError::InvalidSetIndex
+    );
+
+    let current_slot = Clock::get()?.slot;
+
+    // Keep the outgoing set alive for a bounded grace window.
+    current_set.expiry_slot = current_slot + VALIDATOR_SET_GRACE_SLOTS;
+
+    let new_set = &mut ctx.accounts.new_set;
+    new_set.set_index = new_set_index;
+    new_set.validators = validators;
+    new_set.created_slot = current_slot;
+    new_set.expiry_slot = 0;
+    new_set.bump = ctx.bumps.new_set;
+
+    emit!(ValidatorSetUpgraded {
+        new_set_index,
+        previous_set_index: current_set.set_index,
+        validator_count: new_set.validators.len() as u64,
+        previous_expiry_slot: current_set.expiry_slot,
+    });
+
+    msg!(
+        "Validator set rotated: {} -> {}, previous valid until slot {}",
+        current_set.set_index,
+        new_set_index,
+        current_set.expiry_slot
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(new_set_index: u64)]
+pub struct UpgradeValidatorSet<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ValidatorSetPDA::SIZE,
+        seeds = [VALIDATOR_SET_SEED, new_set_index.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub new_set: Account<'info, ValidatorSetPDA>,
+
+    #[account(
+        mut,
+        seeds = [VALIDATOR_SET_SEED, current_set.set_index.to_le_bytes().as_ref()],
+        bump = current_set.bump
+    )]
+    pub current_set: Account<'info, ValidatorSetPDA>,
+
+    // Gateway authority - only they can rotate validator sets
+    #[account(
+        mut,
+        constraint = authority.key() == gateway.authority @ GatewayError::UnauthorizedAccess
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GATEWAY_SEED, gateway.chain_id.to_le_bytes().as_ref()],
+        bump = gateway.bump,
+        constraint = gateway.system_enabled @ GatewayError::GatewayDisabled
+    )]
+    pub gateway: Account<'info, MessageGateway>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,104 @@
+// Anchor's #[program]/entrypoint macros emit cfgs (anchor-debug, custom-heap,
+// custom-panic, solana) that are not declared features of this crate.
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod events;
+pub mod instructions;
+pub mod state;
+pub mod utils;
+
+use instructions::*;
+
+declare_id!("11111111111111111111111111111111");
+
+#[program]
+pub mod message_gateway_v4 {
+    use super::*;
+
+    /// Initializes a counter PDA for a source chain (gateway authority only).
+    pub fn initialize_counter(
+        ctx: Context<InitializeCounter>,
+        source_chain_id: u64,
+    ) -> Result<()> {
+        instructions::initialize_counter::handler(ctx, source_chain_id)
+    }
+
+    /// Installs the genesis validator set (gateway authority only).
+    pub fn initialize_validator_set(
+        ctx: Context<InitializeValidatorSet>,
+        set_index: u64,
+        validators: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::initialize_validator_set::handler(ctx, set_index, validators)
+    }
+
+    /// Rotates the validator set, keeping the previous set valid for a grace
+    /// window (gateway authority only).
+    pub fn upgrade_validator_set(
+        ctx: Context<UpgradeValidatorSet>,
+        new_set_index: u64,
+        validators: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::upgrade_validator_set::handler(ctx, new_set_index, validators)
+    }
+
+    /// Accumulates a batch of verified validator signatures for a message.
+    pub fn post_signatures(
+        ctx: Context<PostSignatures>,
+        source_chain_id: u64,
+        tx_id: u128,
+        active_set_index: u64,
+        message_hash: [u8; 32],
+        indices: Vec<u8>,
+    ) -> Result<()> {
+        instructions::post_signatures::handler(
+            ctx,
+            source_chain_id,
+            tx_id,
+            active_set_index,
+            message_hash,
+            indices,
+        )
+    }
+
+    /// Records a cross-chain message once it carries a signature quorum.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_tx_pda(
+        ctx: Context<CreateTxPda>,
+        tx_id: u128,
+        source_chain_id: u64,
+        dest_chain_id: u64,
+        sender: Vec<u8>,
+        recipient: Vec<u8>,
+        on_chain_data: Vec<u8>,
+        off_chain_data: Vec<u8>,
+        active_set_index: u64,
+        message_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::create_tx_pda::handler(
+            ctx,
+            tx_id,
+            source_chain_id,
+            dest_chain_id,
+            sender,
+            recipient,
+            on_chain_data,
+            off_chain_data,
+            active_set_index,
+            message_hash,
+        )
+    }
+
+    /// Tunes the sliding acceptance window on a counter (gateway authority only).
+    pub fn set_counter_window(
+        ctx: Context<SetCounterWindow>,
+        source_chain_id: u64,
+        accept_window: u128,
+    ) -> Result<()> {
+        instructions::set_counter_window::handler(ctx, source_chain_id, accept_window)
+    }
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+/// Per-source-chain counter. Tracks the highest processed transaction id for
+/// monitoring and the sliding window within which lower, out-of-order ids are
+/// still accepted. Exactly-once semantics are enforced per `tx_id` by the
+/// `TxIdPDA`, not by this counter.
+#[account]
+pub struct CounterPDA {
+    pub source_chain_id: u64,
+    pub highest_tx_id_seen: u128,
+    /// Lag window below `highest_tx_id_seen` within which a lower `tx_id` is
+    /// still accepted; messages older than this are assumed finalized/pruned.
+    pub accept_window: u128,
+    pub bump: u8,
+}
+
+impl CounterPDA {
+    pub const SIZE: usize = 8 + 16 + 16 + 1;
+}
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+/// Root configuration account for a gateway deployment. The `chain_id` is this
+/// deployment's destination chain and seeds the account; `authority` gates the
+/// administrative instructions.
+#[account]
+pub struct MessageGateway {
+    pub chain_id: u64,
+    pub authority: Pubkey,
+    pub system_enabled: bool,
+    pub bump: u8,
+}
+
+impl MessageGateway {
+    pub const SIZE: usize = 8 + 32 + 1 + 1;
+}
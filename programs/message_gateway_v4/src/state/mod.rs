@@ -0,0 +1,11 @@
+pub mod counter;
+pub mod gateway;
+pub mod signature_set;
+pub mod tx_id;
+pub mod validator_set;
+
+pub use counter::*;
+pub use gateway::*;
+pub use signature_set::*;
+pub use tx_id::*;
+pub use validator_set::*;
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_VALIDATORS;
+
+/// The signed-validators bitmap is a `u128`, so the validator cap and the
+/// bitmap width cannot be allowed to drift apart: a larger set would shift by
+/// >= 128 and silently collide signers.
+const _: () = assert!(MAX_VALIDATORS <= 128);
+
+/// Accumulates validator signatures for a single cross-chain message across
+/// several transactions, so large validator sets can clear the ~1232-byte
+/// transaction limit.
+///
+/// Keyed by `(source_chain_id, tx_id)`, the account commits to the
+/// `message_hash` on first write and flips a bit in `signed_bitmap` for each
+/// validator whose signature has been verified. `CreateTxPda` later consumes
+/// the account once the bitmap's popcount reaches quorum and closes it,
+/// refunding rent to the relayer.
+#[account]
+pub struct SignatureSetPDA {
+    pub source_chain_id: u64,
+    pub tx_id: u128,
+    /// Validator set index the accumulated signatures are verified against.
+    pub set_index: u64,
+    /// Message hash every batch committed to; `CreateTxPda` must recompute the
+    /// same value from its arguments or the accumulated signatures are void.
+    pub message_hash: [u8; 32],
+    /// Bit `i` is set once validator `i` has a verified signature on file.
+    pub signed_bitmap: u128,
+    /// False until the first batch commits the message hash and set index.
+    pub initialized: bool,
+    pub bump: u8,
+}
+
+impl SignatureSetPDA {
+    /// Account data size: source_chain_id + tx_id + set_index + message_hash +
+    /// signed_bitmap + initialized + bump.
+    pub const SIZE: usize = 8 + 16 + 8 + 32 + 16 + 1 + 1;
+
+    /// Number of distinct validators that have signed so far.
+    pub fn signed_count(&self) -> u32 {
+        self.signed_bitmap.count_ones()
+    }
+}
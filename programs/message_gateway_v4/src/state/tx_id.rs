@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+/// Proof that a given `tx_id` from a source chain has been processed. Its
+/// `init` in `CreateTxPda` makes re-processing the same `tx_id` impossible,
+/// which is how the gateway guarantees exactly-once delivery.
+#[account]
+pub struct TxIdPDA {
+    pub tx_id: u128,
+    pub bump: u8,
+}
+
+impl TxIdPDA {
+    pub const SIZE: usize = 16 + 1;
+}
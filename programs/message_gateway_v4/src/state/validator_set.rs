@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_VALIDATORS;
+
+/// On-chain representation of the authorized cross-chain signer set.
+///
+/// Sets are versioned by `set_index` (the PDA seed). Installing a new set
+/// increments the index and stamps the previous set with an `expiry_slot`
+/// `VALIDATOR_SET_GRACE_SLOTS` in the future, so messages already signed under
+/// the old set can still be processed while relayers catch up. This mirrors
+/// the guardian-set-upgrade pattern used by other cross-chain bridges.
+#[account]
+pub struct ValidatorSetPDA {
+    /// Monotonic version of this set; also the PDA seed.
+    pub set_index: u64,
+    /// Ordered list of authorized signer public keys.
+    pub validators: Vec<Pubkey>,
+    /// Slot at which this set was installed.
+    pub created_slot: u64,
+    /// Slot after which this set is no longer accepted. `0` while the set is
+    /// the active (latest) set; stamped when a newer set supersedes it.
+    pub expiry_slot: u64,
+    pub bump: u8,
+}
+
+impl ValidatorSetPDA {
+    /// Account data size: set_index + vec length prefix + validators +
+    /// created_slot + expiry_slot + bump.
+    pub const SIZE: usize = 8 + 4 + (32 * MAX_VALIDATORS) + 8 + 8 + 1;
+
+    /// Returns true once `current_slot` falls past this set's grace window.
+    /// An active set (`expiry_slot == 0`) never expires.
+    pub fn is_expired(&self, current_slot: u64) -> bool {
+        self.expiry_slot != 0 && current_slot > self.expiry_slot
+    }
+}
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    ed25519_program,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+
+use crate::errors::GatewayError;
+
+/// Offsets table starts after the `num_signatures` (u8) + padding (u8) header.
+const SIGNATURE_OFFSETS_START: usize = 2;
+/// Size of one `Ed25519SignatureOffsets` entry.
+const SIGNATURE_OFFSETS_SIZE: usize = 14;
+
+/// Walks the instructions sysvar and returns a bitmap of validator indices for
+/// which this transaction carries a valid native Ed25519 verify instruction
+/// over `message_hash`.
+///
+/// Offloading the curve math to the Ed25519 precompile keeps the BPF handler
+/// within its compute budget; here we only cross-check that each precompile
+/// instruction targets the native program, signs exactly `message_hash`, and
+/// names a public key that is a member of the active validator set. A signer
+/// whose pubkey is not in the set is rejected outright, and a signed message
+/// that does not byte-equal `message_hash` fails the verification.
+pub fn verify_ed25519_precompile(
+    instructions_sysvar: &AccountInfo,
+    validators: &[Pubkey],
+    message_hash: &[u8; 32],
+) -> Result<u128> {
+    let current_index = load_current_index_checked(instructions_sysvar)? as usize;
+
+    // u16 sentinel the precompile treats as "the instruction currently being
+    // executed" for the per-field instruction-index references.
+    const IX_INDEX_CURRENT: usize = u16::MAX as usize;
+
+    let mut signers: u128 = 0;
+    for ix_index in 0..current_index {
+        let ix = load_instruction_at_checked(ix_index, instructions_sysvar)?;
+        if ix.program_id != ed25519_program::ID {
+            continue;
+        }
+
+        let data = &ix.data;
+        let num_signatures = *data.first().ok_or(GatewayError::MalformedEd25519Ix)? as usize;
+
+        for i in 0..num_signatures {
+            let base = SIGNATURE_OFFSETS_START + i * SIGNATURE_OFFSETS_SIZE;
+            let offsets = data
+                .get(base..base + SIGNATURE_OFFSETS_SIZE)
+                .ok_or(GatewayError::MalformedEd25519Ix)?;
+
+            let read_u16 = |o: usize| u16::from_le_bytes([offsets[o], offsets[o + 1]]) as usize;
+            let signature_offset = read_u16(0);
+            let signature_ix_index = read_u16(2);
+            let pubkey_offset = read_u16(4);
+            let pubkey_ix_index = read_u16(6);
+            let message_offset = read_u16(8);
+            let message_size = read_u16(10);
+            let message_ix_index = read_u16(12);
+
+            // The precompile verifies the bytes located by the *_instruction_index
+            // fields, not the plaintext we read here. Unless every field points
+            // back at this same instruction (explicitly or via the "current"
+            // sentinel), a relayer could make the precompile check a real
+            // signature held in another instruction while we read an
+            // attacker-chosen pubkey/message from this one - a quorum forgery.
+            let refers_here =
+                |idx: usize| idx == ix_index || idx == IX_INDEX_CURRENT;
+            require!(
+                refers_here(signature_ix_index)
+                    && refers_here(pubkey_ix_index)
+                    && refers_here(message_ix_index),
+                GatewayError::MalformedEd25519Ix
+            );
+
+            // Bind the signature bytes so the offsets describe a fully
+            // self-contained verify instruction.
+            data.get(signature_offset..signature_offset + 64)
+                .ok_or(GatewayError::MalformedEd25519Ix)?;
+
+            let pubkey_bytes = data
+                .get(pubkey_offset..pubkey_offset + 32)
+                .ok_or(GatewayError::MalformedEd25519Ix)?;
+            let message = data
+                .get(message_offset..message_offset + message_size)
+                .ok_or(GatewayError::MalformedEd25519Ix)?;
+
+            require!(
+                message == message_hash,
+                GatewayError::Ed25519MessageMismatch
+            );
+
+            let pubkey =
+                Pubkey::try_from(pubkey_bytes).map_err(|_| GatewayError::MalformedEd25519Ix)?;
+            let index = validators
+                .iter()
+                .position(|v| v == &pubkey)
+                .ok_or(GatewayError::UnknownSigner)?;
+            signers |= 1u128 << index;
+        }
+    }
+
+    Ok(signers)
+}
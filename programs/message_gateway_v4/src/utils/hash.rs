@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+
+/// Computes the canonical signing hash over a cross-chain message's fields.
+///
+/// Validators sign this digest off-chain; the gateway recomputes it from the
+/// instruction arguments so signatures are bound to the exact message being
+/// processed and cannot be replayed against a different payload.
+pub fn create_message_hash_for_signing(
+    tx_id: u128,
+    source_chain_id: u64,
+    dest_chain_id: u64,
+    sender: &[u8],
+    recipient: &[u8],
+    on_chain_data: &[u8],
+    off_chain_data: &[u8],
+) -> Result<[u8; 32]> {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&tx_id.to_le_bytes());
+    buf.extend_from_slice(&source_chain_id.to_le_bytes());
+    buf.extend_from_slice(&dest_chain_id.to_le_bytes());
+
+    // Length-frame each variable-length field so field boundaries cannot be
+    // shifted: without this, adjacent byte-vecs concatenate ambiguously and a
+    // relayer could re-partition e.g. recipient/on_chain_data under the same
+    // signatures to redirect a legitimately-signed message.
+    for field in [sender, recipient, on_chain_data, off_chain_data] {
+        buf.extend_from_slice(&(field.len() as u32).to_le_bytes());
+        buf.extend_from_slice(field);
+    }
+
+    Ok(keccak::hash(&buf).to_bytes())
+}
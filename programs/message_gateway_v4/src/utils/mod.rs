@@ -0,0 +1,3 @@
+pub mod ed25519;
+pub mod hash;
+pub mod signature;
@@ -0,0 +1,6 @@
+/// Minimum number of distinct validator signatures required to accept a
+/// message: a strict super-majority of the set (`floor(N * 2 / 3) + 1`),
+/// matching the guardian-style BFT threshold used across the bridge.
+pub fn quorum_threshold(validator_count: usize) -> usize {
+    validator_count * 2 / 3 + 1
+}